@@ -3,32 +3,26 @@
 async fn open_file_dialog(app: tauri::AppHandle) -> Result<Option<String>, String> {
     use tauri_plugin_dialog::DialogExt;
     
-    let result = std::sync::Arc::new(std::sync::Mutex::new(None));
-    let result_clone = result.clone();
-    
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
     app.dialog().file()
         .set_title("Open File")
         .add_filter("Text Files", &["txt", "md", "js", "ts", "html", "css", "json", "py", "java", "cpp", "c", "h", "rs", "go", "php", "rb", "swift", "kt", "dart", "vue", "jsx", "tsx"])
         .add_filter("All Files", &["*"])
         .pick_file(move |file_path| {
-            let mut result = result_clone.lock().unwrap();
-            *result = file_path.map(|p| p.to_string());
+            let _ = tx.send(file_path.map(|p| p.to_string()));
         });
 
-    // Wait for the dialog to complete (simple approach)
-    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-    
-    let result = result.lock().unwrap().clone();
-    Ok(result)
+    // Resolve exactly when the user dismisses the dialog.
+    rx.await.map_err(|e| format!("Dialog cancelled: {}", e))
 }
 
 #[tauri::command]
 async fn save_file_dialog(app: tauri::AppHandle, default_name: Option<String>) -> Result<Option<String>, String> {
     use tauri_plugin_dialog::DialogExt;
     
-    let result = std::sync::Arc::new(std::sync::Mutex::new(None));
-    let result_clone = result.clone();
-    
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
     let mut dialog = app.dialog().file()
         .set_title("Save File")
         .add_filter("Text Files", &["txt", "md", "js", "ts", "html", "css", "json", "py", "java", "cpp", "c", "h", "rs", "go", "php", "rb", "swift", "kt", "dart", "vue", "jsx", "tsx"])
@@ -39,15 +33,64 @@ async fn save_file_dialog(app: tauri::AppHandle, default_name: Option<String>) -
     }
     
     dialog.save_file(move |file_path| {
-        let mut result = result_clone.lock().unwrap();
-        *result = file_path.map(|p| p.to_string());
+        let _ = tx.send(file_path.map(|p| p.to_string()));
     });
-    
-    // Wait for the dialog to complete
-    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-    
-    let result = result.lock().unwrap().clone();
-    Ok(result)
+
+    // Resolve exactly when the user dismisses the dialog.
+    rx.await.map_err(|e| format!("Dialog cancelled: {}", e))
+}
+
+#[tauri::command]
+async fn open_files_dialog(app: tauri::AppHandle) -> Result<Option<Vec<String>>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    app.dialog().file()
+        .set_title("Open Files")
+        .add_filter("Text Files", &["txt", "md", "js", "ts", "html", "css", "json", "py", "java", "cpp", "c", "h", "rs", "go", "php", "rb", "swift", "kt", "dart", "vue", "jsx", "tsx"])
+        .add_filter("All Files", &["*"])
+        .pick_files(move |file_paths| {
+            let _ = tx.send(
+                file_paths.map(|paths| paths.into_iter().map(|p| p.to_string()).collect()),
+            );
+        });
+
+    // Resolve exactly when the user dismisses the dialog.
+    rx.await.map_err(|e| format!("Dialog cancelled: {}", e))
+}
+
+#[tauri::command]
+fn detect_language_mode(file_path: String) -> String {
+    let extension = std::path::Path::new(&file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let mode = match extension.as_str() {
+        "rs" => "rust",
+        "ts" => "typescript",
+        "tsx" => "tsx",
+        "js" | "jsx" => "javascript",
+        "py" => "python",
+        "java" => "java",
+        "c" | "h" => "c",
+        "cpp" => "cpp",
+        "go" => "go",
+        "php" => "php",
+        "rb" => "ruby",
+        "swift" => "swift",
+        "kt" => "kotlin",
+        "dart" => "dart",
+        "vue" => "vue",
+        "html" => "html",
+        "css" => "css",
+        "json" => "json",
+        "md" => "markdown",
+        _ => "text",
+    };
+    mode.to_string()
 }
 
 #[tauri::command]
@@ -56,18 +99,502 @@ async fn read_file_content(file_path: String) -> Result<String, String> {
         .map_err(|e| format!("Failed to read file: {}", e))
 }
 
+#[derive(serde::Serialize)]
+struct FileContent {
+    text: String,
+    encoding: String,
+}
+
+#[tauri::command]
+async fn read_file_content_encoded(file_path: String) -> Result<FileContent, String> {
+    let bytes = std::fs::read(&file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    // Detect an explicit BOM first, then fall back to UTF-8 / Latin-1 so that
+    // non-UTF-8 files open instead of hard-failing like `read_to_string` would.
+    // The UTF-32 BOMs must be checked before the UTF-16 ones: a UTF-32LE BOM
+    // (`FF FE 00 00`) starts with the UTF-16LE BOM (`FF FE`) and would otherwise
+    // be misdetected. `chunks_exact(2)` drops a trailing odd byte on malformed
+    // UTF-16 input, which is acceptable for a lossy decode. The returned encoding
+    // label is fed straight back to `write_file_content` so the file round-trips,
+    // including the distinct `UTF-8-BOM` label that carries the BOM through.
+    let (text, encoding) = if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        (String::from_utf8_lossy(&bytes[3..]).into_owned(), "UTF-8-BOM")
+    } else if bytes.starts_with(&[0xFF, 0xFE, 0x00, 0x00]) {
+        let text: String = bytes[4..]
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .map(|u| char::from_u32(u).unwrap_or('\u{FFFD}'))
+            .collect();
+        (text, "UTF-32LE")
+    } else if bytes.starts_with(&[0x00, 0x00, 0xFE, 0xFF]) {
+        let text: String = bytes[4..]
+            .chunks_exact(4)
+            .map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]]))
+            .map(|u| char::from_u32(u).unwrap_or('\u{FFFD}'))
+            .collect();
+        (text, "UTF-32BE")
+    } else if bytes.starts_with(&[0xFF, 0xFE]) {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        (String::from_utf16_lossy(&units), "UTF-16LE")
+    } else if bytes.starts_with(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect();
+        (String::from_utf16_lossy(&units), "UTF-16BE")
+    } else {
+        // No BOM: keep valid UTF-8 as-is, otherwise decode as Latin-1 (ISO-8859-1)
+        // where every byte maps 1:1 to a codepoint. Unlike `from_utf8_lossy` this is
+        // lossless, so a Latin-1 file round-trips instead of turning high bytes into
+        // U+FFFD and being mislabelled `UTF-8`.
+        match std::str::from_utf8(&bytes) {
+            Ok(s) => (s.to_string(), "UTF-8"),
+            Err(_) => (bytes.iter().map(|&b| b as char).collect(), "ISO-8859-1"),
+        }
+    };
+
+    Ok(FileContent {
+        text,
+        encoding: encoding.to_string(),
+    })
+}
+
+// Encode `content` for writing in the same `encoding` that `read_file_content_encoded`
+// reported, re-emitting the matching BOM so BOM/UTF-16/UTF-32/Latin-1 files round-trip.
+// Unknown or absent encodings fall back to plain UTF-8.
+fn encode_content(content: &str, encoding: Option<&str>) -> Vec<u8> {
+    match encoding.map(str::to_ascii_uppercase).as_deref() {
+        Some("UTF-8-BOM") => {
+            let mut out = vec![0xEF, 0xBB, 0xBF];
+            out.extend_from_slice(content.as_bytes());
+            out
+        }
+        Some("ISO-8859-1") => content
+            .chars()
+            .map(|c| if (c as u32) <= 0xFF { c as u8 } else { b'?' })
+            .collect(),
+        Some("UTF-16LE") => {
+            let mut out = vec![0xFF, 0xFE];
+            for unit in content.encode_utf16() {
+                out.extend_from_slice(&unit.to_le_bytes());
+            }
+            out
+        }
+        Some("UTF-16BE") => {
+            let mut out = vec![0xFE, 0xFF];
+            for unit in content.encode_utf16() {
+                out.extend_from_slice(&unit.to_be_bytes());
+            }
+            out
+        }
+        Some("UTF-32LE") => {
+            let mut out = vec![0xFF, 0xFE, 0x00, 0x00];
+            for ch in content.chars() {
+                out.extend_from_slice(&(ch as u32).to_le_bytes());
+            }
+            out
+        }
+        Some("UTF-32BE") => {
+            let mut out = vec![0x00, 0x00, 0xFE, 0xFF];
+            for ch in content.chars() {
+                out.extend_from_slice(&(ch as u32).to_be_bytes());
+            }
+            out
+        }
+        _ => content.as_bytes().to_vec(),
+    }
+}
+
+#[tauri::command]
+async fn write_file_content(
+    file_path: String,
+    content: String,
+    encoding: Option<String>,
+    keep_backup: Option<bool>,
+) -> Result<(), String> {
+    use std::io::Write;
+
+    let path = std::path::Path::new(&file_path);
+    let parent = path.parent().unwrap_or_else(|| std::path::Path::new("."));
+    let bytes = encode_content(&content, encoding.as_deref());
+
+    // Inspect the existing target (without following symlinks) to decide how to save.
+    let existing = std::fs::symlink_metadata(path).ok();
+
+    if let Some(meta) = &existing {
+        // A read-only target would be silently clobbered by an atomic rename (which only
+        // needs directory write permission), unlike the old in-place write. Refuse it so
+        // the `is_readonly` reporting from `get_file_metadata` is actually honoured.
+        if meta.permissions().readonly() {
+            return Err("File is read-only".to_string());
+        }
+        // Renaming over a symlink replaces the link with a regular file, breaking it.
+        // Write in place instead so the link (and its target) are preserved.
+        if meta.file_type().is_symlink() {
+            return std::fs::write(path, &bytes)
+                .map_err(|e| format!("Failed to write file: {}", e));
+        }
+    }
+
+    // Write to a sibling temp file in the same directory so the final rename is atomic.
+    let temp_path = match path.file_name() {
+        Some(name) => parent.join(format!(".{}.tmp", name.to_string_lossy())),
+        None => return Err("Invalid file path".to_string()),
+    };
+
+    {
+        let mut file = std::fs::File::create(&temp_path)
+            .map_err(|e| format!("Failed to create temp file: {}", e))?;
+        file.write_all(&bytes)
+            .map_err(|e| format!("Failed to write file: {}", e))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to flush file: {}", e))?;
+    }
+
+    // Preserve the original file's permissions; `File::create` uses fresh umask perms,
+    // so without this a `0600`/executable file would silently become `0644` after save.
+    if let Some(meta) = &existing {
+        if let Err(e) = std::fs::set_permissions(&temp_path, meta.permissions()) {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(format!("Failed to preserve permissions: {}", e));
+        }
+    }
+
+    // Optionally preserve the previous contents before swapping the new file in.
+    if keep_backup.unwrap_or(false) && existing.is_some() {
+        let backup_path = parent.join(format!(
+            "{}.bak",
+            path.file_name().unwrap().to_string_lossy()
+        ));
+        if let Err(e) = std::fs::copy(path, &backup_path) {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(format!("Failed to write backup: {}", e));
+        }
+    }
+
+    std::fs::rename(&temp_path, path).map_err(|e| {
+        let _ = std::fs::remove_file(&temp_path);
+        format!("Failed to save file: {}", e)
+    })
+}
+
+#[derive(serde::Serialize)]
+struct FileMetaData {
+    path: String,
+    name: String,
+    size: u64,
+    is_readonly: bool,
+    created: Option<u64>,
+    modified: Option<u64>,
+    accessed: Option<u64>,
+    line_count: Option<usize>,
+    encoding: String,
+    #[cfg(unix)]
+    mode_octal: String,
+    #[cfg(unix)]
+    mode_rwx: String,
+}
+
+// Convert a `SystemTime` into epoch-seconds so it crosses the IPC boundary as a plain number.
+fn epoch_seconds(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+}
+
+#[cfg(unix)]
+fn mode_rwx_string(mode: u32) -> String {
+    const FLAGS: [(u32, char); 9] = [
+        (0o400, 'r'), (0o200, 'w'), (0o100, 'x'),
+        (0o040, 'r'), (0o020, 'w'), (0o010, 'x'),
+        (0o004, 'r'), (0o002, 'w'), (0o001, 'x'),
+    ];
+    FLAGS
+        .iter()
+        .map(|(bit, ch)| if mode & bit != 0 { *ch } else { '-' })
+        .collect()
+}
+
+// Identify a file's encoding from its leading BOM without reading the whole file.
+// Defaults to UTF-8 when no BOM is present (the common case).
+fn detect_encoding(path: &std::path::Path) -> &'static str {
+    use std::io::Read;
+    let mut prefix = [0u8; 4];
+    let read = std::fs::File::open(path)
+        .and_then(|mut f| f.read(&mut prefix))
+        .unwrap_or(0);
+    let prefix = &prefix[..read];
+    if prefix.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        "UTF-8"
+    } else if prefix.starts_with(&[0xFF, 0xFE, 0x00, 0x00]) {
+        "UTF-32LE"
+    } else if prefix.starts_with(&[0x00, 0x00, 0xFE, 0xFF]) {
+        "UTF-32BE"
+    } else if prefix.starts_with(&[0xFF, 0xFE]) {
+        "UTF-16LE"
+    } else if prefix.starts_with(&[0xFE, 0xFF]) {
+        "UTF-16BE"
+    } else {
+        "UTF-8"
+    }
+}
+
+// Count newlines by streaming the file so large files don't get materialized into memory.
+fn count_lines(path: &std::path::Path) -> std::io::Result<usize> {
+    use std::io::Read;
+    let mut file = std::fs::File::open(path)?;
+    let mut buffer = [0u8; 64 * 1024];
+    let mut lines = 0usize;
+    let mut last: Option<u8> = None;
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        lines += bytecount_newlines(&buffer[..read]);
+        last = Some(buffer[read - 1]);
+    }
+    // Count a final line that isn't newline-terminated, matching `str::lines`.
+    if matches!(last, Some(b) if b != b'\n') {
+        lines += 1;
+    }
+    Ok(lines)
+}
+
+fn bytecount_newlines(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&b| b == b'\n').count()
+}
+
 #[tauri::command]
-async fn write_file_content(file_path: String, content: String) -> Result<(), String> {
-    std::fs::write(&file_path, content)
-        .map_err(|e| format!("Failed to write file: {}", e))
+async fn get_file_metadata(
+    file_path: String,
+    include_line_count: Option<bool>,
+) -> Result<FileMetaData, String> {
+    let path = std::path::Path::new(&file_path);
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| format!("Failed to read metadata: {}", e))?;
+
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    // Counting lines requires scanning the file, so only do it when the caller asks.
+    let line_count = if include_line_count.unwrap_or(false) {
+        count_lines(path).ok()
+    } else {
+        None
+    };
+
+    #[cfg(unix)]
+    let (mode_octal, mode_rwx) = {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode() & 0o777;
+        (format!("{:o}", mode), mode_rwx_string(mode))
+    };
+
+    Ok(FileMetaData {
+        path: file_path,
+        name,
+        size: metadata.len(),
+        is_readonly: metadata.permissions().readonly(),
+        created: epoch_seconds(metadata.created()),
+        modified: epoch_seconds(metadata.modified()),
+        accessed: epoch_seconds(metadata.accessed()),
+        line_count,
+        encoding: detect_encoding(path).to_string(),
+        #[cfg(unix)]
+        mode_octal,
+        #[cfg(unix)]
+        mode_rwx,
+    })
+}
+
+#[derive(Clone, serde::Serialize)]
+struct FileChangeEvent {
+    path: String,
+    kind: String,
+}
+
+// Active file watchers keyed by path, held in managed state so repeated
+// `watch_file` calls for the same path don't leak watchers. The watcher is shared
+// with its debounce thread (behind `Arc<Mutex>`) so the thread can re-arm a
+// file-level watch after a rename replaces the inode.
+type SharedWatcher = std::sync::Arc<std::sync::Mutex<notify::RecommendedWatcher>>;
+
+#[derive(Default)]
+struct WatcherRegistry(std::sync::Mutex<std::collections::HashMap<String, SharedWatcher>>);
+
+#[tauri::command]
+fn watch_file(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, WatcherRegistry>,
+    path: String,
+) -> Result<(), String> {
+    use notify::{EventKind, RecursiveMode, Watcher};
+    use tauri::Emitter;
+
+    let mut watchers = registry.0.lock().unwrap();
+    if watchers.contains_key(&path) {
+        return Ok(());
+    }
+
+    let target = std::path::PathBuf::from(&path);
+
+    // Forward raw events into a channel and let a dedicated thread debounce them, so a
+    // burst of writes (or a metadata-only event) settles into a single trailing emit.
+    let (tx, rx) = std::sync::mpsc::channel::<&'static str>();
+
+    let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let event = match res {
+            Ok(event) => event,
+            Err(e) => {
+                log::error!("File watch error: {}", e);
+                return;
+            }
+        };
+
+        let kind = match event.kind {
+            EventKind::Modify(_) => "modified",
+            EventKind::Create(_) => "created",
+            EventKind::Remove(_) => "deleted",
+            _ => return,
+        };
+
+        let _ = tx.send(kind);
+    })
+    .map_err(|e| format!("Failed to create watcher: {}", e))?;
+
+    let watcher: SharedWatcher = std::sync::Arc::new(std::sync::Mutex::new(watcher));
+
+    // Watch the file directly rather than its parent directory: watching the whole
+    // parent `NonRecursive` would wake this closure on every change anywhere in a large
+    // dir (e.g. `$HOME`/Downloads) and filter by name. A file-level watch avoids those
+    // wasted wakeups but dies when an atomic rename swaps the inode, so the debounce
+    // thread re-arms it when a burst settles on a `deleted` event below (rename events
+    // are reported as `modified` and do not re-arm).
+    watcher
+        .lock()
+        .unwrap()
+        .watch(&target, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch file: {}", e))?;
+
+    let emit_app = app.clone();
+    let event_path = path.clone();
+    let weak = std::sync::Arc::downgrade(&watcher);
+    // Debounce thread: emit only after a 200ms quiet window; exits when the watcher
+    // (and thus the sender) is dropped by `unwatch_file`. It holds only a `Weak` ref so
+    // removal from the registry actually drops the watcher.
+    std::thread::spawn(move || {
+        let debounce = std::time::Duration::from_millis(200);
+        while let Ok(mut kind) = rx.recv() {
+            // Drain the settling burst, keeping the most recent change kind.
+            while let Ok(next) = rx.recv_timeout(debounce) {
+                kind = next;
+            }
+            let _ = emit_app.emit(
+                "file-changed",
+                FileChangeEvent {
+                    path: event_path.clone(),
+                    kind: kind.to_string(),
+                },
+            );
+
+            // A rename/replace drops the inode-level watch; re-arm it on the path so the
+            // watcher keeps firing (e.g. after this editor's own atomic save).
+            if kind == "deleted" {
+                match weak.upgrade() {
+                    Some(w) => {
+                        use notify::Watcher;
+                        let mut w = w.lock().unwrap();
+                        let path = std::path::Path::new(&event_path);
+                        let _ = w.unwatch(path);
+                        if let Err(e) = w.watch(path, notify::RecursiveMode::NonRecursive) {
+                            log::warn!("Failed to re-arm watch on {}: {}", event_path, e);
+                        }
+                    }
+                    None => break,
+                }
+            }
+        }
+    });
+
+    watchers.insert(path, watcher);
+    Ok(())
+}
+
+#[tauri::command]
+fn unwatch_file(registry: tauri::State<'_, WatcherRegistry>, path: String) -> Result<(), String> {
+    // Dropping the watcher stops the underlying OS notification.
+    registry.0.lock().unwrap().remove(&path);
+    Ok(())
+}
+
+// Resolve a directory for crash logs: `$XDG_DATA_HOME`, then `$HOME/.local/share`,
+// then `%LOCALAPPDATA%`, falling back to the OS temp dir so we always have somewhere
+// to write.
+fn crash_log_dir() -> std::path::PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(std::path::PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| std::path::PathBuf::from(h).join(".local/share")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(std::path::PathBuf::from))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("notepad")
+}
+
+// Install a panic hook that persists the payload and a backtrace so field crashes
+// can be diagnosed even when the dev console isn't available.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let backtrace = std::backtrace::Backtrace::force_capture();
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+            .unwrap_or_else(|| "unknown location".to_string());
+        let payload = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            *s
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.as_str()
+        } else {
+            "Box<dyn Any>"
+        };
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let report = format!(
+            "notepad panic at {}\ntimestamp: {}\nmessage: {}\n\nbacktrace:\n{}\n",
+            location, timestamp, payload, backtrace
+        );
+
+        log::error!("{}", report);
+
+        let dir = crash_log_dir();
+        let _ = std::fs::create_dir_all(&dir);
+        // Overwrite the stable file so users can always grab the latest crash,
+        // and keep a timestamped copy for history.
+        let _ = std::fs::write(dir.join("notepad-crash.log"), &report);
+        let _ = std::fs::write(dir.join(format!("notepad-crash-{}.log", timestamp)), &report);
+
+        default_hook(info);
+    }));
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    install_panic_hook();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
+        .manage(WatcherRegistry::default())
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -80,9 +607,15 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             open_file_dialog,
+            open_files_dialog,
+            detect_language_mode,
             save_file_dialog,
             read_file_content,
-            write_file_content
+            read_file_content_encoded,
+            write_file_content,
+            get_file_metadata,
+            watch_file,
+            unwatch_file
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");